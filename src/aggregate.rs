@@ -0,0 +1,112 @@
+with_std! {
+    use std::fmt::{self, Debug, Display};
+    use std::vec::Vec;
+
+    use crate::{Error, Fail};
+
+    /// An error that aggregates zero or more other errors.
+    ///
+    /// Built by `collect_failures` and `AggregateResultExt::aggregate_context`,
+    /// for situations where it's more useful to see everything that went
+    /// wrong in one pass than to bail out on the first failure. Where
+    /// `Context<D>` can only ever wrap a single cause, `AggregateError`
+    /// collects as many as were encountered.
+    pub struct AggregateError {
+        errors: Vec<Error>,
+    }
+
+    impl AggregateError {
+        fn single<E: Fail>(error: E) -> AggregateError {
+            AggregateError {
+                errors: vec![Error::from(error)],
+            }
+        }
+
+        /// Returns an iterator over the wrapped errors.
+        pub fn iter(&self) -> impl Iterator<Item = &Error> {
+            self.errors.iter()
+        }
+    }
+
+    impl IntoIterator for AggregateError {
+        type Item = Error;
+        type IntoIter = std::vec::IntoIter<Error>;
+
+        /// Consumes the aggregate, returning an iterator over the wrapped
+        /// errors.
+        fn into_iter(self) -> Self::IntoIter {
+            self.errors.into_iter()
+        }
+    }
+
+    impl Fail for AggregateError {}
+
+    impl Display for AggregateError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self.errors.len() {
+                1 => write!(f, "1 error occurred"),
+                n => write!(f, "{} errors occurred", n),
+            }
+        }
+    }
+
+    impl Debug for AggregateError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            for (i, error) in self.errors.iter().enumerate() {
+                if i > 0 {
+                    writeln!(f)?;
+                }
+                write!(f, "{}: {:?}", i, error)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Extension methods for turning a single `Result` into an
+    /// `AggregateError`, so it composes with `collect_failures`.
+    pub trait AggregateResultExt<T> {
+        /// Wraps the error, with context attached, as the sole entry of a
+        /// fresh `AggregateError`.
+        fn aggregate_context<D>(self, context: D) -> Result<T, AggregateError>
+        where
+            D: Display + Send + Sync + 'static;
+    }
+
+    impl<T, E> AggregateResultExt<T> for Result<T, E>
+    where
+        E: Fail,
+    {
+        fn aggregate_context<D>(self, context: D) -> Result<T, AggregateError>
+        where
+            D: Display + Send + Sync + 'static,
+        {
+            self.map_err(|failure| AggregateError::single(failure.context(context)))
+        }
+    }
+
+    /// Drains a fallible iterator, collecting every error into an
+    /// `AggregateError` instead of stopping at the first one.
+    ///
+    /// Returns `Ok` with every successful item only if none of them failed.
+    pub fn collect_failures<I, T, E>(iter: I) -> Result<Vec<T>, AggregateError>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+        E: Fail,
+    {
+        let mut oks = Vec::new();
+        let mut errors = Vec::new();
+
+        for item in iter {
+            match item {
+                Ok(value) => oks.push(value),
+                Err(error) => errors.push(Error::from(error)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(oks)
+        } else {
+            Err(AggregateError { errors })
+        }
+    }
+}
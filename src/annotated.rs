@@ -0,0 +1,166 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display};
+
+use crate::Fail;
+
+enum Note {
+    Note(Box<dyn Display + Send + Sync>),
+    Suggestion(Box<dyn Display + Send + Sync>),
+}
+
+impl Display for Note {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Note::Note(note) => write!(f, "= note: {}", note),
+            Note::Suggestion(suggestion) => write!(f, "= help: {}", suggestion),
+        }
+    }
+}
+
+/// A `Fail` wrapped together with advisory notes and suggestions, in the
+/// style of `color-eyre`'s sections and `miette`'s help text.
+///
+/// `Display` and `Debug` are forwarded unchanged to the wrapped cause, so
+/// `Annotated` is transparent to anything that only ever prints the error
+/// itself; [`Annotated::report`] is the opt-in adapter that also renders
+/// the notes.
+///
+/// Each `AnnotatedExt` method wraps the error in a fresh `Annotated` that
+/// holds just that one note or suggestion. Chaining several calls nests
+/// `Annotated` values instead of extending one list, and `report` renders
+/// only the notes of the `Annotated` it is called on; the notes of inner
+/// layers are reachable through `Fail::cause` but are not printed.
+pub struct Annotated<E> {
+    cause: E,
+    notes: Vec<Note>,
+}
+
+impl<E: Fail> Annotated<E> {
+    fn new(cause: E) -> Annotated<E> {
+        Annotated {
+            cause,
+            notes: Vec::new(),
+        }
+    }
+
+    /// Returns a `Display` adapter that renders the wrapped error followed
+    /// by its notes and suggestions as indented `= note:` / `= help:`
+    /// lines, the way a CLI would want to print it for a user.
+    pub fn report(&self) -> Report<'_, E> {
+        Report(self)
+    }
+}
+
+impl<E: Fail> Fail for Annotated<E> {
+    fn cause(&self) -> Option<&dyn Fail> {
+        Some(&self.cause)
+    }
+}
+
+impl<E: Fail> Display for Annotated<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.cause, f)
+    }
+}
+
+impl<E: Fail> Debug for Annotated<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.cause, f)
+    }
+}
+
+/// A `Display` adapter, produced by [`Annotated::report`], that renders
+/// the error followed by its `= note:` / `= help:` lines.
+pub struct Report<'a, E>(&'a Annotated<E>);
+
+impl<'a, E: Fail> Display for Report<'a, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.cause)?;
+        for note in &self.0.notes {
+            write!(f, "\n  {}", note)?;
+        }
+        Ok(())
+    }
+}
+
+/// Extension methods for attaching advisory notes and suggestions to a
+/// failing `Result`, without treating them as the error's primary
+/// `Display` message.
+pub trait AnnotatedExt<T, E> {
+    /// Attaches a note, e.g. background information about why the failure
+    /// happened.
+    fn note<D>(self, note: D) -> Result<T, Annotated<E>>
+    where
+        D: Display + Send + Sync + 'static;
+
+    /// Attaches a note generated by looking at the error value.
+    fn with_note<F, D>(self, f: F) -> Result<T, Annotated<E>>
+    where
+        F: FnOnce(&E) -> D,
+        D: Display + Send + Sync + 'static;
+
+    /// Attaches a suggestion, e.g. something the caller could try next.
+    fn suggestion<D>(self, suggestion: D) -> Result<T, Annotated<E>>
+    where
+        D: Display + Send + Sync + 'static;
+
+    /// Attaches a suggestion generated by looking at the error value.
+    fn with_suggestion<F, D>(self, f: F) -> Result<T, Annotated<E>>
+    where
+        F: FnOnce(&E) -> D,
+        D: Display + Send + Sync + 'static;
+}
+
+impl<T, E> AnnotatedExt<T, E> for Result<T, E>
+where
+    E: Fail,
+{
+    fn note<D>(self, note: D) -> Result<T, Annotated<E>>
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|failure| {
+            let mut annotated = Annotated::new(failure);
+            annotated.notes.push(Note::Note(Box::new(note)));
+            annotated
+        })
+    }
+
+    fn with_note<F, D>(self, f: F) -> Result<T, Annotated<E>>
+    where
+        F: FnOnce(&E) -> D,
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|failure| {
+            let note = f(&failure);
+            let mut annotated = Annotated::new(failure);
+            annotated.notes.push(Note::Note(Box::new(note)));
+            annotated
+        })
+    }
+
+    fn suggestion<D>(self, suggestion: D) -> Result<T, Annotated<E>>
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|failure| {
+            let mut annotated = Annotated::new(failure);
+            annotated.notes.push(Note::Suggestion(Box::new(suggestion)));
+            annotated
+        })
+    }
+
+    fn with_suggestion<F, D>(self, f: F) -> Result<T, Annotated<E>>
+    where
+        F: FnOnce(&E) -> D,
+        D: Display + Send + Sync + 'static,
+    {
+        self.map_err(|failure| {
+            let suggestion = f(&failure);
+            let mut annotated = Annotated::new(failure);
+            annotated.notes.push(Note::Suggestion(Box::new(suggestion)));
+            annotated
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use alloc::boxed::Box;
+use core::fmt::{self, Debug, Display};
+use core::panic::Location;
+
+use crate::Fail;
+
+/// A wrapper around a context value and, optionally, the error that caused
+/// it. `Display` shows only the context value.
+///
+/// Produced by `ResultExt::context` / `OptionExt::context` and friends; the
+/// wrapped cause, if any, is reachable through `Fail::cause`.
+pub struct Context<D> {
+    context: D,
+    cause: Option<Box<dyn Fail>>,
+    location: Option<&'static Location<'static>>,
+}
+
+impl<D: Display + Send + Sync + 'static> Context<D> {
+    /// Creates a new `Context` from a context value, with no underlying
+    /// cause. This is what backs `OptionExt::context`, where a `None` has
+    /// nothing to wrap.
+    pub fn new(context: D) -> Context<D> {
+        Context {
+            context,
+            cause: None,
+            location: None,
+        }
+    }
+
+    pub(crate) fn with_cause<F: Fail>(context: D, cause: F) -> Context<D> {
+        Context {
+            context,
+            cause: Some(Box::new(cause)),
+            location: None,
+        }
+    }
+
+    /// Like `with_cause`, but also records a location previously captured
+    /// by the caller (via `#[track_caller]`). Kept as a plain fn, rather
+    /// than `#[track_caller]` itself, so that `Location::caller()` is
+    /// captured at the real `?`/call site instead of wherever this
+    /// constructor happens to get called from (e.g. inside a `map_err`
+    /// closure).
+    pub(crate) fn with_cause_at<F: Fail>(
+        context: D,
+        cause: F,
+        location: &'static Location<'static>,
+    ) -> Context<D> {
+        Context {
+            context,
+            cause: Some(Box::new(cause)),
+            location: Some(location),
+        }
+    }
+
+    /// Attaches a previously-captured location to an already-built
+    /// `Context`. Used for the `failure::Error` case, where the cause
+    /// can't be boxed through `with_cause_at` (`Error` isn't `Fail`) and
+    /// the `Context` instead comes from `Error`'s own `.context()`.
+    pub(crate) fn located(mut self, location: &'static Location<'static>) -> Context<D> {
+        self.location = Some(location);
+        self
+    }
+
+    /// Returns a reference to the context value.
+    pub fn get_context(&self) -> &D {
+        &self.context
+    }
+}
+
+impl<D: Display + Send + Sync + 'static> Fail for Context<D> {
+    fn cause(&self) -> Option<&dyn Fail> {
+        self.cause.as_ref().map(|cause| cause.as_ref())
+    }
+}
+
+impl<D: Display + Send + Sync + 'static> Display for Context<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.context, f)
+    }
+}
+
+impl<D: Display + Send + Sync + 'static> Debug for Context<D> {
+    /// Prints the context message, and, if it was captured through
+    /// `context_located`, the `file:line:column` of the call site that
+    /// attached it. Recursing into the wrapped cause (when that cause is
+    /// itself a located `Context`) reconstructs a full "where did this
+    /// error pass through" trail out of nothing but `#[track_caller]`
+    /// locations, which stays useful even in `strip`ped release binaries
+    /// that carry no real backtrace.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.location {
+            Some(location) => write!(
+                f,
+                "{} @ {}:{}:{}",
+                self.context,
+                location.file(),
+                location.line(),
+                location.column()
+            )?,
+            None => write!(f, "{}", self.context)?,
+        }
+
+        if let Some(cause) = &self.cause {
+            write!(f, "\ncaused by: {:?}", cause)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use core::fmt::Display;
+
+use crate::Context;
+
+/// Extension methods for `Option`.
+///
+/// This is the `Option` analogue of `ResultExt`: it lets a missing value be
+/// turned directly into a contextful error, without first routing through
+/// `.ok_or_else(...)?.context(...)`.
+pub trait OptionExt<T> {
+    /// Wraps a missing value in a context type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "std", feature = "derive"))]
+    /// # #[macro_use] extern crate failure;
+    /// #
+    /// # fn main() {
+    /// #    tests::run_test();
+    /// # }
+    /// #
+    /// # #[cfg(not(all(feature = "std", feature = "derive")))] mod tests { pub fn run_test() { } }
+    /// #
+    /// # #[cfg(all(feature = "std", feature = "derive"))] mod tests {
+    /// #
+    /// # use failure::{self, OptionExt};
+    /// #
+    /// # pub fn run_test() {
+    ///
+    /// let x: Option<i32> = None;
+    /// let x = x.context("missing value").unwrap_err();
+    ///
+    /// let x = format!("{}", x);
+    ///
+    /// assert_eq!(x, "missing value");
+    /// # }
+    ///
+    /// # }
+    /// ```
+    fn context<D>(self, context: D) -> Result<T, Context<D>>
+    where
+        D: Display + Send + Sync + 'static;
+
+    /// Wraps a missing value in a context type generated by a closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "std", feature = "derive"))]
+    /// # #[macro_use] extern crate failure;
+    /// #
+    /// # fn main() {
+    /// #    tests::run_test();
+    /// # }
+    /// #
+    /// # #[cfg(not(all(feature = "std", feature = "derive")))] mod tests { pub fn run_test() { } }
+    /// #
+    /// # #[cfg(all(feature = "std", feature = "derive"))] mod tests {
+    /// #
+    /// # use failure::{self, OptionExt};
+    /// #
+    /// # pub fn run_test() {
+    ///
+    /// let x: Option<i32> = None;
+    /// let x = x.with_context(|| format!("missing {}", "value")).unwrap_err();
+    ///
+    /// let x = format!("{}", x);
+    ///
+    /// assert_eq!(x, "missing value");
+    /// # }
+    ///
+    /// # }
+    /// ```
+    fn with_context<F, D>(self, f: F) -> Result<T, Context<D>>
+    where
+        F: FnOnce() -> D,
+        D: Display + Send + Sync + 'static;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn context<D>(self, context: D) -> Result<T, Context<D>>
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        self.ok_or_else(|| Context::new(context))
+    }
+
+    fn with_context<F, D>(self, f: F) -> Result<T, Context<D>>
+    where
+        F: FnOnce() -> D,
+        D: Display + Send + Sync + 'static,
+    {
+        self.ok_or_else(|| Context::new(f()))
+    }
+}
@@ -147,6 +147,58 @@ pub trait ResultExt<T, E> {
     where
         F: FnOnce(&E) -> D,
         D: Display + Send + Sync + 'static;
+
+    /// Wraps the error type in a context type, recording the call site's
+    /// file, line and column (via `#[track_caller]` and `Location::caller`).
+    ///
+    /// Each hop through `.context_located(...)` leaves a breadcrumb that the
+    /// `Debug` impl of `Context` prints as `message @ file:line:column`,
+    /// building up a pseudo-backtrace out of nothing but source locations.
+    /// That makes it useful even in `strip`ped release binaries, where a
+    /// real backtrace may not be available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "std", feature = "derive"))]
+    /// # #[macro_use] extern crate failure;
+    /// #
+    /// # fn main() {
+    /// #    tests::run_test();
+    /// # }
+    /// #
+    /// # #[cfg(not(all(feature = "std", feature = "derive")))] mod tests { pub fn run_test() { } }
+    /// #
+    /// # #[cfg(all(feature = "std", feature = "derive"))] mod tests {
+    /// #
+    /// # use failure::{self, ResultExt};
+    /// #
+    /// #[derive(Fail, Debug)]
+    /// #[fail(display = "")]
+    /// struct CustomError;
+    /// #
+    /// # pub fn run_test() {
+    ///
+    /// let result = (|| -> Result<(), failure::Error> {
+    ///     Err(CustomError)?
+    /// })();
+    /// let (x, line) = (result.context_located("an error occurred").unwrap_err(), line!());
+    ///
+    /// let x = format!("{:?}", x);
+    ///
+    /// // The recorded location is this doctest's own call site, not the
+    /// // line inside `context_located`'s implementation.
+    /// assert!(x.starts_with("an error occurred @"));
+    /// assert!(x.contains(&format!("{}:{}:", file!(), line)));
+    /// assert!(x.contains(file!()));
+    /// # }
+    ///
+    /// # }
+    /// ```
+    #[track_caller]
+    fn context_located<D>(self, context: D) -> Result<T, Context<D>>
+    where
+        D: Display + Send + Sync + 'static;
 }
 
 impl<T, E> ResultExt<T, E> for Result<T, E>
@@ -174,6 +226,15 @@ where
             failure.context(context)
         })
     }
+
+    #[track_caller]
+    fn context_located<D>(self, context: D) -> Result<T, Context<D>>
+    where
+        D: Display + Send + Sync + 'static,
+    {
+        let location = core::panic::Location::caller();
+        self.map_err(|failure| Context::with_cause_at(context, failure, location))
+    }
 }
 
 with_std! {
@@ -199,5 +260,13 @@ with_std! {
                 failure.context(context)
             })
         }
+
+        #[track_caller]
+        fn context_located<D>(self, context: D) -> Result<T, Context<D>> where
+            D: Display + Send + Sync + 'static
+        {
+            let location = core::panic::Location::caller();
+            self.map_err(|failure| failure.context(context).located(location))
+        }
     }
 }